@@ -9,16 +9,25 @@
     rust_2018_idioms
 )]
 
+extern crate alloc;
+
+use alloc::{format, string::ToString};
+
 use asr::{
     file_format::pe,
     future::{next_tick, retry},
     settings::Gui,
     signature::Signature,
+    time::Duration,
     timer::{self, TimerState},
     watcher::Watcher,
     Address, Address32, Process,
 };
 
+mod state_machine;
+
+use state_machine::{Action, RunState, Transition};
+
 asr::panic_handler!();
 asr::async_main!(nightly);
 
@@ -39,31 +48,72 @@ async fn main() {
                 // Perform memory scanning to look for the addresses we need
                 let addresses = Addresses::init(&process).await;
 
+                // Tracks in-game time independently of real time, accumulated across
+                // completed acts so it survives menu/transition stalls.
+                let mut game_time = GameTime::default();
+
+                // Tracks which phase of a run we're in; drives which transitions
+                // in TRANSITIONS are even eligible to be evaluated. Seeded from the
+                // real timer state rather than assumed NotStarted, so attaching (or
+                // re-attaching after the game process closed) while LiveSplit is
+                // already Running/Paused - a crash mid-run, a manual start/reset by
+                // the runner, or just reloading the splitter mid-attempt - doesn't
+                // get stuck offering only the NotStarted -> InRun transition, which
+                // is itself gated to a NotRunning timer and so could never fire.
+                let mut run_state = match timer::state() {
+                    TimerState::Running | TimerState::Paused => RunState::InRun,
+                    _ => RunState::NotStarted,
+                };
+
                 loop {
                     // Splitting logic. Adapted from OG LiveSplit:
                     // Order of execution
                     // 1. update() will always be run first. There are no conditions on the execution of this action.
-                    // 2. If the timer is currently either running or paused, then the isLoading, gameTime, and reset actions will be run.
-                    // 3. If reset does not return true, then the split action will be run.
-                    // 4. If the timer is currently not running (and not paused), then the start action will be run.
+                    // 2. If the timer is currently either running or paused, then the isLoading and gameTime actions will be run.
+                    // 3. The state machine is stepped once, evaluating transitions out of the current run state in
+                    //    priority order and firing the first whose guard passes (start/split/reset, gated the same
+                    //    way OG LiveSplit gated those actions).
                     settings.update();
-                    update_loop(&process, &addresses, &mut watchers);
+                    update_loop(&process, &addresses, &mut watchers, &settings, &game_time);
 
                     let timer_state = timer::state();
-                    if timer_state == TimerState::Running || timer_state == TimerState::Paused {
-                        if reset(&watchers, &settings, &addresses) {
-                            timer::reset()
-                        } else if split(&watchers, &settings) {
-                            timer::split()
+
+                    // Follow starts and resets the runner makes by hand, or a run that
+                    // ended on its last split and was then reset: with no run in
+                    // progress the machine is back at NotStarted and no frames stay
+                    // banked for the next run.
+                    match timer_state {
+                        TimerState::NotRunning => {
+                            run_state = RunState::NotStarted;
+                            game_time = GameTime::default();
+                        }
+                        TimerState::Running | TimerState::Paused
+                            if run_state == RunState::NotStarted =>
+                        {
+                            run_state = RunState::InRun;
                         }
+                        _ => {}
                     }
 
-                    if timer::state() == TimerState::NotRunning
-                        && start(&watchers, &settings, &addresses)
-                    {
-                        timer::start();
+                    if timer_state == TimerState::Running || timer_state == TimerState::Paused {
+                        update_game_time(&watchers, &game_time);
                     }
 
+                    let transitions = match settings.run_mode {
+                        RunMode::FullGame => TRANSITIONS,
+                        RunMode::IndividualLevel => IL_TRANSITIONS,
+                    };
+
+                    run_state = state_machine::step(
+                        run_state,
+                        transitions,
+                        &watchers,
+                        &settings,
+                        &addresses,
+                        &mut game_time,
+                        timer_state,
+                    );
+
                     next_tick().await;
                 }
             })
@@ -72,7 +122,16 @@ async fn main() {
 }
 
 #[derive(asr::settings::Gui)]
-struct Settings {
+pub(crate) struct Settings {
+    #[default = "FullGame"]
+    /// Run Mode
+    run_mode: RunMode,
+    #[default = "GreenHill1"]
+    /// IL Mode --> Target act
+    il_target_act: IlAct,
+    #[default = false]
+    /// Debug --> Show verbose variables
+    show_debug_variables: bool,
     #[default = true]
     /// Start --> New Game
     start_clean_save: bool,
@@ -83,6 +142,12 @@ struct Settings {
     /// Reset --> Enable auto reset
     reset: bool,
     #[default = true]
+    /// Split --> Special stage entry
+    split_on_special_stage_enter: bool,
+    #[default = true]
+    /// Split --> Chaos Emerald collected
+    split_on_emerald: bool,
+    #[default = true]
     /// Green Hill Zone - Act 1
     green_hill_1: bool,
     #[default = true]
@@ -142,21 +207,38 @@ struct Settings {
 }
 
 #[derive(Default)]
-struct Watchers {
+pub(crate) struct Watchers {
     state: Watcher<u8>,
     levelid: Watcher<Acts>,
     zoneselectongamecomplete: Watcher<u8>,
     zoneindicator: Watcher<ZoneIndicator>,
+    level_frames: Watcher<u32>,
+    rings: Watcher<u16>,
+    emeralds: Watcher<u8>,
+    special_stage: Watcher<bool>,
 }
 
-struct Addresses {
+pub(crate) struct Addresses {
     state: Address,
     levelid: Address,
     zoneselectongamecomplete: Address,
     zoneindicator: Address,
+    level_frames: Address,
+    rings: Address,
+    emeralds: Address,
     game_version: GameVersion,
 }
 
+/// Accumulates in-game time (load-removed) across completed acts: an act's
+/// final frame count is banked when its act-transition split fires, so an act
+/// whose split is turned off in the settings is not banked. The currently
+/// running act's elapsed frames are added on top of this when reporting game
+/// time, and it is zeroed out on every run reset.
+#[derive(Default)]
+pub(crate) struct GameTime {
+    accumulated_frames: u32,
+}
+
 impl Addresses {
     async fn init(process: &Process) -> Self {
         let main_module_base = retry(|| {
@@ -232,6 +314,9 @@ impl Addresses {
                     let state = pointer_path(ptr, lea, 0, 0, 0x9EC, false).await;
                     let levelid = pointer_path(ptr, lea, 0x4 * 123, 2, 0, false).await;
                     let zoneselectongamecomplete = Address::NULL;
+                    let level_frames = pointer_path(ptr, lea, 0, 0, 0x9F0, false).await;
+                    let rings = pointer_path(ptr, lea, 0, 0, 0x9F4, false).await;
+                    let emeralds = pointer_path(ptr, lea, 0, 0, 0x9F8, false).await;
 
                     const SIG_64_3: Signature<15> =
                         Signature::new("C6 05 ???????? ?? E9 ???????? 48 8D 0D");
@@ -243,6 +328,9 @@ impl Addresses {
                         levelid,
                         zoneselectongamecomplete,
                         zoneindicator,
+                        level_frames,
+                        rings,
+                        emeralds,
                         game_version,
                     }
                 }
@@ -257,6 +345,9 @@ impl Addresses {
                     let state = pointer_path(ptr, lea, 0x4 * 73, 8, 0x9D8, true).await;
                     let levelid = pointer_path(ptr, lea, 0x4 * 123, 1, 0, true).await;
                     let zoneselectongamecomplete = Address::NULL;
+                    let level_frames = pointer_path(ptr, lea, 0x4 * 73, 8, 0x9DC, true).await;
+                    let rings = pointer_path(ptr, lea, 0x4 * 73, 8, 0x9E0, true).await;
+                    let emeralds = pointer_path(ptr, lea, 0x4 * 73, 8, 0x9E4, true).await;
 
                     const SIG32_2: Signature<7> = Signature::new("69 F8 ???????? B8");
                     let ptr = retry(|| SIG32_2.scan_process_range(process, main_module)).await + 7;
@@ -267,6 +358,9 @@ impl Addresses {
                         levelid,
                         zoneselectongamecomplete,
                         zoneindicator,
+                        level_frames,
+                        rings,
+                        emeralds,
                         game_version,
                     }
                 }
@@ -281,6 +375,9 @@ impl Addresses {
                 let state = pointer_path(ptr, lea, 0x4 * 30, 8, 0x9D8, true).await;
                 let levelid = pointer_path(ptr, lea, 0x4 * 123, 1, 0, true).await;
                 let zoneselectongamecomplete = pointer_path(ptr, lea, 0x4 * 18, 3, 4, true).await;
+                let level_frames = pointer_path(ptr, lea, 0x4 * 30, 8, 0x9DC, true).await;
+                let rings = pointer_path(ptr, lea, 0x4 * 30, 8, 0x9E0, true).await;
+                let emeralds = pointer_path(ptr, lea, 0x4 * 30, 8, 0x9E4, true).await;
 
                 const SIG32_2: Signature<7> = Signature::new("69 F8 ???????? B8");
                 let ptr = retry(|| SIG32_2.scan_process_range(process, main_module)).await + 7;
@@ -291,6 +388,9 @@ impl Addresses {
                     levelid,
                     zoneselectongamecomplete,
                     zoneindicator,
+                    level_frames,
+                    rings,
+                    emeralds,
                     game_version,
                 }
             }
@@ -298,7 +398,13 @@ impl Addresses {
     }
 }
 
-fn update_loop(game: &Process, addresses: &Addresses, watchers: &mut Watchers) {
+fn update_loop(
+    game: &Process,
+    addresses: &Addresses,
+    watchers: &mut Watchers,
+    settings: &Settings,
+    game_time: &GameTime,
+) {
     watchers
         .state
         .update_infallible(game.read(addresses.state).unwrap_or_default());
@@ -319,6 +425,7 @@ fn update_loop(game: &Process, addresses: &Addresses, watchers: &mut Watchers) {
                 Ok(0x656E6F5A) => ZoneIndicator::Zones,
                 Ok(0x69646E45) => ZoneIndicator::Ending,
                 Ok(0x65766153) => ZoneIndicator::SaveSelect,
+                Ok(0x63657053) => ZoneIndicator::SpecialStage,
                 _ => ZoneIndicator::Default,
             });
 
@@ -351,9 +458,187 @@ fn update_loop(game: &Process, addresses: &Addresses, watchers: &mut Watchers) {
             _ => Acts::Default,
         },
     });
+
+    watchers
+        .level_frames
+        .update_infallible(game.read(addresses.level_frames).unwrap_or_default());
+    watchers
+        .rings
+        .update_infallible(game.read(addresses.rings).unwrap_or_default());
+    watchers
+        .emeralds
+        .update_infallible(game.read(addresses.emeralds).unwrap_or_default());
+    watchers
+        .special_stage
+        .update_infallible(zone.current == ZoneIndicator::SpecialStage);
+
+    update_variables(watchers, settings, game_time);
+}
+
+/// Publishes the splitter's internal view of the run to LiveSplit as
+/// variables, surfacing what the auto-splitter sees - invaluable when a
+/// signature scan resolves to the wrong address on an unrecognized build.
+/// Zone/act identity is always shown; the rest is gated behind
+/// `Settings::show_debug_variables` so normal users aren't overwhelmed.
+fn update_variables(watchers: &Watchers, settings: &Settings, game_time: &GameTime) {
+    timer::set_variable(
+        "Zone Indicator",
+        match watchers.zoneindicator.pair.map(|pair| pair.current) {
+            Some(ZoneIndicator::MainMenu) => "Main Menu",
+            Some(ZoneIndicator::Zones) => "Zones",
+            Some(ZoneIndicator::Ending) => "Ending",
+            Some(ZoneIndicator::SaveSelect) => "Save Select",
+            Some(ZoneIndicator::SpecialStage) => "Special Stage",
+            _ => "Unknown",
+        },
+    );
+    timer::set_variable(
+        "Current Act",
+        watchers
+            .levelid
+            .pair
+            .map_or("Unknown", |pair| act_name(pair.current)),
+    );
+
+    if !settings.show_debug_variables {
+        return;
+    }
+
+    if let Some(state) = &watchers.state.pair {
+        timer::set_variable("Raw State", &state.current.to_string());
+    }
+    if let Some(rings) = &watchers.rings.pair {
+        timer::set_variable("Rings", &rings.current.to_string());
+    }
+    if let Some(emeralds) = &watchers.emeralds.pair {
+        timer::set_variable("Emeralds", &emeralds.current.to_string());
+    }
+    if let Some(level_frames) = &watchers.level_frames.pair {
+        timer::set_variable(
+            "Act Elapsed Frames",
+            &format!(
+                "{} (+{} banked)",
+                level_frames.current, game_time.accumulated_frames
+            ),
+        );
+    }
+}
+
+/// Human-readable name for an act, used for the `Current Act` variable.
+fn act_name(act: Acts) -> &'static str {
+    match act {
+        Acts::GreenHill1 => "Green Hill Zone - Act 1",
+        Acts::GreenHill2 => "Green Hill Zone - Act 2",
+        Acts::GreenHill3 => "Green Hill Zone - Act 3",
+        Acts::Marble1 => "Marble Zone - Act 1",
+        Acts::Marble2 => "Marble Zone - Act 2",
+        Acts::Marble3 => "Marble Zone - Act 3",
+        Acts::SpringYard1 => "Spring Yard Zone - Act 1",
+        Acts::SpringYard2 => "Spring Yard Zone - Act 2",
+        Acts::SpringYard3 => "Spring Yard Zone - Act 3",
+        Acts::Labyrinth1 => "Labyrinth Zone - Act 1",
+        Acts::Labyrinth2 => "Labyrinth Zone - Act 2",
+        Acts::Labyrinth3 => "Labyrinth Zone - Act 3",
+        Acts::StarLight1 => "Star Light Zone - Act 1",
+        Acts::StarLight2 => "Star Light Zone - Act 2",
+        Acts::StarLight3 => "Star Light Zone - Act 3",
+        Acts::ScrapBrain1 => "Scrap Brain Zone - Act 1",
+        Acts::ScrapBrain2 => "Scrap Brain Zone - Act 2",
+        Acts::ScrapBrain3 => "Scrap Brain Zone - Act 3",
+        Acts::FinalZone => "Final Zone",
+        Acts::Default => "Unknown",
+    }
+}
+
+/// Drives the in-game-time (load-removed) timer from the level-frame counter.
+/// Mirrors the isLoading/gameTime actions from OG LiveSplit: paused in menus
+/// and during the act-transition window before the new level's timer starts,
+/// otherwise resumed and reported as `(accumulated_frames + level_frames) / 60`
+/// seconds (except in Final Zone, see below).
+fn update_game_time(watchers: &Watchers, game_time: &GameTime) {
+    let Some(zone) = &watchers.zoneindicator.pair else {
+        return;
+    };
+    let Some(level_frames) = &watchers.level_frames.pair else {
+        return;
+    };
+
+    match zone.current {
+        ZoneIndicator::MainMenu | ZoneIndicator::SaveSelect => timer::pause_game_time(),
+        ZoneIndicator::Zones => match watchers.levelid.pair.map(|pair| pair.current) {
+            // Final Zone has no on-screen timer, so its frame counter is not
+            // read: game time is only resumed and runs on in real time from
+            // the value last reported through the last completed act.
+            Some(Acts::FinalZone) => timer::resume_game_time(),
+            _ => {
+                if level_frames.current == 0 || level_frames.current < level_frames.old {
+                    timer::pause_game_time();
+                } else {
+                    timer::resume_game_time();
+                    timer::set_game_time(Duration::seconds_f64(
+                        (game_time.accumulated_frames + level_frames.current) as f64 / 60.0,
+                    ));
+                }
+            }
+        },
+        // Ending / special-stage transitions: the in-level counter isn't
+        // valid here, so freeze the previously reported game time instead of
+        // feeding it garbage.
+        _ => timer::pause_game_time(),
+    }
 }
 
-fn start(watchers: &Watchers, settings: &Settings, addresses: &Addresses) -> bool {
+/// Transitions out of `RunState::NotStarted`, `InRun` and `Completed`,
+/// evaluated by `state_machine::step` once per tick in this priority order.
+/// Version-specific constants and per-act splits live entirely as guard
+/// logic here rather than duplicated across separate start/split/reset
+/// functions; new categories (e.g. IL mode) register their own table
+/// instead of editing this one.
+const TRANSITIONS: &[Transition] = &[
+    Transition {
+        from: RunState::NotStarted,
+        to: RunState::InRun,
+        guard: guard_start,
+        action: Action::Start,
+    },
+    Transition {
+        from: RunState::InRun,
+        to: RunState::NotStarted,
+        guard: guard_reset,
+        action: Action::Reset,
+    },
+    Transition {
+        from: RunState::InRun,
+        to: RunState::Completed,
+        guard: guard_split_final,
+        action: Action::Split,
+    },
+    Transition {
+        from: RunState::InRun,
+        to: RunState::InRun,
+        guard: guard_split_milestone,
+        action: Action::Split,
+    },
+    Transition {
+        from: RunState::InRun,
+        to: RunState::InRun,
+        guard: guard_split,
+        action: Action::Split,
+    },
+    Transition {
+        from: RunState::Completed,
+        to: RunState::NotStarted,
+        guard: guard_reset,
+        action: Action::Reset,
+    },
+];
+
+fn guard_start(
+    watchers: &Watchers,
+    settings: &Settings,
+    addresses: &Addresses,
+    _game_time: &mut GameTime,
+) -> bool {
     let Some(state) = &watchers.state.pair else {
         return false;
     };
@@ -386,8 +671,15 @@ fn start(watchers: &Watchers, settings: &Settings, addresses: &Addresses) -> boo
     }
 }
 
-fn split(watchers: &Watchers, settings: &Settings) -> bool {
-    watchers.levelid.pair.is_some_and(|levelid| match levelid.current {
+/// Act-to-act progression splits (all of them except the Final Zone finish,
+/// see `guard_split_final`).
+fn guard_split(
+    watchers: &Watchers,
+    settings: &Settings,
+    _addresses: &Addresses,
+    game_time: &mut GameTime,
+) -> bool {
+    let split = watchers.levelid.pair.is_some_and(|levelid| match levelid.current {
         Acts::GreenHill2 => settings.green_hill_1 && levelid.old == Acts::GreenHill1,
         Acts::GreenHill3 => settings.green_hill_2 && levelid.old == Acts::GreenHill2,
         Acts::Marble1 => settings.green_hill_3 && levelid.old == Acts::GreenHill3,
@@ -406,13 +698,79 @@ fn split(watchers: &Watchers, settings: &Settings) -> bool {
         Acts::ScrapBrain2 => settings.scrap_brain_1 && levelid.old == Acts::ScrapBrain1,
         Acts::ScrapBrain3 => settings.scrap_brain_2 && levelid.old == Acts::ScrapBrain2,
         Acts::FinalZone => settings.scrap_brain_3 && levelid.old == Acts::ScrapBrain3,
-        Acts::Default => settings.final_zone && levelid.old != levelid.current,
         _ => false,
-    })
+    });
+
+    if split {
+        bank_level_frames(watchers, game_time);
+    }
+
+    split
 }
 
-fn reset(watchers: &Watchers, settings: &Settings, addresses: &Addresses) -> bool {
-    settings.reset
+/// Special-stage entries and Chaos Emerald pickups, for the all-emeralds
+/// route. Fires alongside the regular act-progression splits without
+/// needing to know where in the act order the player currently is.
+fn guard_split_milestone(
+    watchers: &Watchers,
+    settings: &Settings,
+    _addresses: &Addresses,
+    _game_time: &mut GameTime,
+) -> bool {
+    let entered_special_stage = settings.split_on_special_stage_enter
+        && watchers
+            .special_stage
+            .pair
+            .is_some_and(|pair| pair.changed_from_to(&false, &true));
+    let collected_emerald = settings.split_on_emerald
+        && watchers
+            .emeralds
+            .pair
+            .is_some_and(|pair| pair.current > pair.old);
+
+    // Unlike an act transition, neither event resets the in-level frame
+    // counter, so there's nothing to bank here - level_frames keeps
+    // counting through the milestone and accumulated_frames must not.
+    entered_special_stage || collected_emerald
+}
+
+/// The run-ending split: `levelid` dropping to `Acts::Default` (the Ending, or
+/// a level id outside the known acts) from any other act. In a full run that
+/// is the exit out of Final Zone.
+fn guard_split_final(
+    watchers: &Watchers,
+    settings: &Settings,
+    _addresses: &Addresses,
+    game_time: &mut GameTime,
+) -> bool {
+    let split = settings.final_zone
+        && watchers
+            .levelid
+            .pair
+            .is_some_and(|levelid| levelid.current == Acts::Default && levelid.old != levelid.current);
+
+    if split {
+        bank_level_frames(watchers, game_time);
+    }
+
+    split
+}
+
+/// Banks the just-finished act's final frame count into the game-time
+/// accumulator so it keeps counting across the act transition.
+fn bank_level_frames(watchers: &Watchers, game_time: &mut GameTime) {
+    if let Some(level_frames) = &watchers.level_frames.pair {
+        game_time.accumulated_frames = game_time.accumulated_frames.saturating_add(level_frames.old);
+    }
+}
+
+fn guard_reset(
+    watchers: &Watchers,
+    settings: &Settings,
+    addresses: &Addresses,
+    game_time: &mut GameTime,
+) -> bool {
+    let should_reset = settings.reset
         && match addresses.game_version {
             GameVersion::Below1_5_0 => {
                 watchers
@@ -434,7 +792,144 @@ fn reset(watchers: &Watchers, settings: &Settings, addresses: &Addresses) -> boo
                         .pair
                         .is_some_and(|val| val.current == ZoneIndicator::SaveSelect)
             }
-        }
+        };
+
+    if should_reset {
+        game_time.accumulated_frames = 0;
+    }
+
+    should_reset
+}
+
+/// Transitions for `RunMode::IndividualLevel`: a self-contained table for the
+/// practice/IL category, registered alongside `TRANSITIONS` instead of
+/// threading a mode flag through the full-game guards.
+const IL_TRANSITIONS: &[Transition] = &[
+    Transition {
+        from: RunState::NotStarted,
+        to: RunState::InRun,
+        guard: guard_il_start,
+        action: Action::Start,
+    },
+    Transition {
+        from: RunState::InRun,
+        to: RunState::NotStarted,
+        guard: guard_il_reset,
+        action: Action::Reset,
+    },
+    Transition {
+        from: RunState::InRun,
+        to: RunState::Completed,
+        guard: guard_il_split,
+        action: Action::Split,
+    },
+    Transition {
+        from: RunState::Completed,
+        to: RunState::NotStarted,
+        guard: guard_il_completed_reset,
+        action: Action::Reset,
+    },
+];
+
+fn guard_il_start(
+    watchers: &Watchers,
+    settings: &Settings,
+    _addresses: &Addresses,
+    _game_time: &mut GameTime,
+) -> bool {
+    let target: Acts = settings.il_target_act.into();
+
+    watchers.levelid.pair.is_some_and(|levelid| levelid.current == target)
+        && watchers.level_frames.pair.is_some_and(|frames| frames.current == 0)
+}
+
+fn guard_il_split(
+    watchers: &Watchers,
+    settings: &Settings,
+    _addresses: &Addresses,
+    game_time: &mut GameTime,
+) -> bool {
+    let target: Acts = settings.il_target_act.into();
+
+    let split = watchers
+        .levelid
+        .pair
+        .is_some_and(|levelid| levelid.old == target && levelid.current != target)
+        && watchers
+            .zoneindicator
+            .pair
+            .is_some_and(|zone| zone.current == ZoneIndicator::Zones);
+
+    if split {
+        bank_level_frames(watchers, game_time);
+    }
+
+    split
+}
+
+fn guard_il_reset(
+    watchers: &Watchers,
+    settings: &Settings,
+    _addresses: &Addresses,
+    game_time: &mut GameTime,
+) -> bool {
+    let target: Acts = settings.il_target_act.into();
+
+    // `levelid` only gets updated by `update_loop` while `zoneindicator` reads
+    // `Zones`, so it keeps reporting `target` after quitting out to the pause
+    // menu instead of ever reporting a different act - detect the abandoned
+    // attempt off `zoneindicator` leaving `Zones` directly.
+    let left_to_menu = watchers
+        .zoneindicator
+        .pair
+        .is_some_and(|zone| zone.old == ZoneIndicator::Zones && zone.current != ZoneIndicator::Zones);
+    let restarted_mid_attempt = watchers.levelid.pair.is_some_and(|levelid| levelid.current == target)
+        && watchers
+            .level_frames
+            .pair
+            .is_some_and(|frames| frames.current == 0 && frames.old != 0);
+
+    let should_reset = left_to_menu || restarted_mid_attempt;
+
+    if should_reset {
+        game_time.accumulated_frames = 0;
+    }
+
+    should_reset
+}
+
+/// Fires once an IL attempt has completed and the player either backs out to
+/// the menu or re-enters the target act to go again, so `IL_TRANSITIONS` can
+/// loop back to `NotStarted` instead of leaving `guard_il_start` permanently
+/// unreachable for the rest of the session.
+fn guard_il_completed_reset(
+    watchers: &Watchers,
+    settings: &Settings,
+    _addresses: &Addresses,
+    game_time: &mut GameTime,
+) -> bool {
+    let target: Acts = settings.il_target_act.into();
+
+    let left_to_menu = watchers
+        .zoneindicator
+        .pair
+        .is_some_and(|zone| zone.current != ZoneIndicator::Zones);
+    let reentered_target = watchers
+        .levelid
+        .pair
+        .is_some_and(|levelid| levelid.current == target)
+        && watchers
+            .level_frames
+            .pair
+            .is_some_and(|frames| frames.current == 0);
+
+    let should_reset = left_to_menu || reentered_target;
+
+    if should_reset {
+        game_time.accumulated_frames = 0;
+    }
+
+    should_reset
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -443,6 +938,7 @@ enum ZoneIndicator {
     Zones,
     Ending,
     SaveSelect,
+    SpecialStage,
     Default,
 }
 
@@ -470,6 +966,85 @@ enum Acts {
     Default,
 }
 
+#[derive(asr::settings::Gui, Clone, Copy, PartialEq)]
+pub(crate) enum RunMode {
+    /// Full Game
+    FullGame,
+    /// Individual Level
+    IndividualLevel,
+}
+
+/// Acts selectable as the IL-mode target. Mirrors `Acts` one-to-one, minus
+/// the non-selectable `Default` placeholder, so it can be exposed as a Gui
+/// setting.
+#[derive(asr::settings::Gui, Clone, Copy, PartialEq)]
+pub(crate) enum IlAct {
+    /// Green Hill Zone - Act 1
+    GreenHill1,
+    /// Green Hill Zone - Act 2
+    GreenHill2,
+    /// Green Hill Zone - Act 3
+    GreenHill3,
+    /// Marble Zone - Act 1
+    Marble1,
+    /// Marble Zone - Act 2
+    Marble2,
+    /// Marble Zone - Act 3
+    Marble3,
+    /// Spring Yard Zone - Act 1
+    SpringYard1,
+    /// Spring Yard Zone - Act 2
+    SpringYard2,
+    /// Spring Yard Zone - Act 3
+    SpringYard3,
+    /// Labyrinth Zone - Act 1
+    Labyrinth1,
+    /// Labyrinth Zone - Act 2
+    Labyrinth2,
+    /// Labyrinth Zone - Act 3
+    Labyrinth3,
+    /// Star Light Zone - Act 1
+    StarLight1,
+    /// Star Light Zone - Act 2
+    StarLight2,
+    /// Star Light Zone - Act 3
+    StarLight3,
+    /// Scrap Brain Zone - Act 1
+    ScrapBrain1,
+    /// Scrap Brain Zone - Act 2
+    ScrapBrain2,
+    /// Scrap Brain Zone - Act 3
+    ScrapBrain3,
+    /// Final Zone
+    FinalZone,
+}
+
+impl From<IlAct> for Acts {
+    fn from(act: IlAct) -> Self {
+        match act {
+            IlAct::GreenHill1 => Acts::GreenHill1,
+            IlAct::GreenHill2 => Acts::GreenHill2,
+            IlAct::GreenHill3 => Acts::GreenHill3,
+            IlAct::Marble1 => Acts::Marble1,
+            IlAct::Marble2 => Acts::Marble2,
+            IlAct::Marble3 => Acts::Marble3,
+            IlAct::SpringYard1 => Acts::SpringYard1,
+            IlAct::SpringYard2 => Acts::SpringYard2,
+            IlAct::SpringYard3 => Acts::SpringYard3,
+            IlAct::Labyrinth1 => Acts::Labyrinth1,
+            IlAct::Labyrinth2 => Acts::Labyrinth2,
+            IlAct::Labyrinth3 => Acts::Labyrinth3,
+            IlAct::StarLight1 => Acts::StarLight1,
+            IlAct::StarLight2 => Acts::StarLight2,
+            IlAct::StarLight3 => Acts::StarLight3,
+            IlAct::ScrapBrain1 => Acts::ScrapBrain1,
+            IlAct::ScrapBrain2 => Acts::ScrapBrain2,
+            IlAct::ScrapBrain3 => Acts::ScrapBrain3,
+            IlAct::FinalZone => Acts::FinalZone,
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 enum GameVersion {
     Below1_5_0,
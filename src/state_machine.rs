@@ -0,0 +1,78 @@
+//! Small state-machine subsystem driving the timer, modelled after the
+//! states/transitions/guards/actions shape used by dotLottie's state
+//! machine. Version-specific constants and per-act rules live as guard data
+//! next to the rest of the game logic; this module only knows how to pick
+//! and fire the first matching transition each tick.
+
+use crate::{Addresses, GameTime, Settings, Watchers};
+use asr::timer::{self, TimerState};
+
+/// High-level phase of a run. The game's own internal state codes, level
+/// ids, etc. never appear here - they're only ever inspected inside a
+/// transition's guard.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RunState {
+    NotStarted,
+    InRun,
+    Completed,
+}
+
+/// What to do against the `asr::timer` API when a transition's guard passes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Action {
+    Start,
+    Split,
+    Reset,
+    Pause,
+}
+
+/// One edge of the run's state machine.
+pub(crate) struct Transition {
+    pub(crate) from: RunState,
+    pub(crate) to: RunState,
+    pub(crate) guard: fn(&Watchers, &Settings, &Addresses, &mut GameTime) -> bool,
+    pub(crate) action: Action,
+}
+
+/// Evaluates `transitions` against `state` in priority order and fires the
+/// first one whose guard passes, returning the resulting state. `timer_state`
+/// restricts which actions are even eligible to fire this tick: `Start` only
+/// while the timer isn't running, everything else only while it's running or
+/// paused - the same gating OG LiveSplit applied to its start/split/reset
+/// actions.
+pub(crate) fn step(
+    state: RunState,
+    transitions: &[Transition],
+    watchers: &Watchers,
+    settings: &Settings,
+    addresses: &Addresses,
+    game_time: &mut GameTime,
+    timer_state: TimerState,
+) -> RunState {
+    for transition in transitions {
+        if transition.from != state {
+            continue;
+        }
+
+        let eligible = match transition.action {
+            Action::Start => timer_state == TimerState::NotRunning,
+            Action::Split | Action::Reset | Action::Pause => {
+                matches!(timer_state, TimerState::Running | TimerState::Paused)
+            }
+        };
+        if !eligible || !(transition.guard)(watchers, settings, addresses, game_time) {
+            continue;
+        }
+
+        match transition.action {
+            Action::Start => timer::start(),
+            Action::Split => timer::split(),
+            Action::Reset => timer::reset(),
+            Action::Pause => timer::pause_game_time(),
+        }
+
+        return transition.to;
+    }
+
+    state
+}